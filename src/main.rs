@@ -1,33 +1,283 @@
 use reqwest::blocking::Client;
 #[allow(unused_imports)]
 use reqwest::Error as ReqwestError;
+use serde::Deserialize;
 use serde_json::Value;
 use std::collections::HashMap;
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::fmt;
+use std::fs;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
+use std::time::Duration;
 use structopt::StructOpt;
 use url::{ParseError, Url};
 
+// A parsed request host: IPv4, IPv6 (optionally with a zone id), or a domain name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Host {
+    Ipv4(Ipv4Addr),
+    Ipv6(Ipv6Addr, Option<String>),
+    Domain(String),
+}
+
+impl Host {
+    // Renders the host as a URL authority, always bracketing IPv6 literals (RFC 3986),
+    // since a default port means `port` is `None` even though a host still follows.
+    fn to_authority(&self, port: Option<u16>) -> String {
+        let host = match self {
+            Host::Ipv6(_, _) => format!("[{}]", self),
+            _ => format!("{}", self),
+        };
+        match port {
+            Some(port) => format!("{}:{}", host, port),
+            None => host,
+        }
+    }
+}
+
+impl fmt::Display for Host {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Host::Ipv4(addr) => write!(f, "{}", addr),
+            Host::Ipv6(addr, Some(zone)) => write!(f, "{}%{}", addr, zone),
+            Host::Ipv6(addr, None) => write!(f, "{}", addr),
+            Host::Domain(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl FromStr for Host {
+    type Err = String;
+
+    // Parses a bare host token like `example.com`, `127.0.0.1`, `::1`, or `[::1]:8080`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let host_part = if let Some(rest) = s.strip_prefix('[') {
+            match rest.find(']') {
+                Some(end) => &rest[..end],
+                None => return Err("The URL contains an invalid IPv6 address.".to_string()),
+            }
+        } else if s.matches(':').count() > 1 {
+            s
+        } else {
+            s.split(':').next().unwrap_or(s)
+        };
+
+        if host_part.is_empty() {
+            return Err("The URL does not contain a host.".to_string());
+        }
+
+        if let Ok(addr) = Ipv4Addr::from_str(host_part) {
+            return Ok(Host::Ipv4(addr));
+        }
+
+        let (addr_part, zone) = match host_part.split_once('%') {
+            Some((addr, zone)) => (addr, Some(zone.to_string())),
+            None => (host_part, None),
+        };
+
+        if let Ok(addr) = Ipv6Addr::from_str(addr_part) {
+            return Ok(Host::Ipv6(addr, zone));
+        }
+
+        if host_part.contains(':') || s.starts_with('[') {
+            // Looked like an IPv6 literal (bracketed, or multiple colons) but failed to parse.
+            return Err("The URL contains an invalid IPv6 address.".to_string());
+        }
+
+        if host_part.chars().all(|c| c.is_ascii_digit() || c == '.') {
+            // Looked like a dotted-quad IPv4 literal but failed to parse.
+            return Err("The URL contains an invalid IPv4 address.".to_string());
+        }
+
+        Ok(Host::Domain(host_part.to_string()))
+    }
+}
+
+// A named set of defaults (base URL, headers, method) selected with `--profile`.
+#[derive(Debug, Deserialize, Default, Clone)]
+struct Profile {
+    #[serde(default)]
+    url: Option<String>,
+    #[serde(default)]
+    method: Option<String>,
+    #[serde(default)]
+    headers: Vec<String>,
+}
+
+// Defaults loaded from the `--config` file; overridden by `--profile`, then by CLI flags.
+#[derive(Debug, Deserialize, Default)]
+struct Config {
+    #[serde(default)]
+    method: Option<String>,
+    #[serde(default)]
+    headers: Vec<String>,
+    #[serde(default)]
+    proxy: Option<String>,
+    #[serde(default)]
+    timeout: Option<u64>,
+    #[serde(default)]
+    profiles: HashMap<String, Profile>,
+}
+
+// Function to locate the default config file (`~/.web_client.json`) when
+// `--config` was not given.
+fn default_config_path() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".web_client.json"))
+}
+
+// Function to load and deserialize the config file at `path`.
+fn load_config(path: &Path) -> Result<Config, String> {
+    let contents = fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read config file '{}': {}", path.display(), e))?;
+    serde_json::from_str(&contents)
+        .map_err(|e| format!("Failed to parse config file '{}': {}", path.display(), e))
+}
+
+// Function to report an unknown `--profile` name, listing what is actually
+// available so the merge order (config defaults < profile < CLI flag) is
+// easy to reason about from the error alone.
+fn unknown_profile_error(name: &str, config: &Config) -> String {
+    let mut names: Vec<&String> = config.profiles.keys().collect();
+    names.sort();
+    let available = if names.is_empty() {
+        "none defined".to_string()
+    } else {
+        names
+            .iter()
+            .map(|s| s.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    format!(
+        "Unknown profile '{}'. Values are merged as config defaults < profile < CLI flags; available profiles: {}.",
+        name, available
+    )
+}
+
 #[derive(StructOpt, Debug)]
 #[structopt(name = "curl", about = "A simple curl command-line tool in Rust")]
 struct CurlArgs {
     #[structopt(name = "url")]
-    url: String,
+    url: Option<String>,
 
-    /// HTTP method to use (GET, POST, etc.)
-    #[structopt(short = "X", long = "request", default_value = "GET")]
-    method: String,
+    /// HTTP method to use (GET, POST, etc.). Defaults to the profile's method,
+    /// then the config file's method, then GET.
+    #[structopt(short = "X", long = "request")]
+    method: Option<String>,
 
     /// Data to send with POST request in the form 'key1=value1&key2=value2'
     #[structopt(short = "d", long = "data")]
     data: Option<String>,
+
+    /// Extra header to send, in the form 'Name: Value'. May be repeated.
+    #[structopt(short = "H", long = "header")]
+    headers: Vec<String>,
+
+    /// HTTP proxy to route the request through, e.g. 'http://proxy:8080'.
+    #[structopt(short = "x", long = "proxy")]
+    proxy: Option<String>,
+
+    /// Resolve HOST:PORT to ADDR, e.g. 'example.com:443:127.0.0.1'. May be repeated.
+    #[structopt(long = "resolve")]
+    resolve: Vec<String>,
+
+    /// Path to a JSON config file with default flags and named profiles. Defaults to '~/.web_client.json'.
+    #[structopt(long = "config")]
+    config: Option<String>,
+
+    /// Named profile from the config file to use as defaults.
+    #[structopt(long = "profile")]
+    profile: Option<String>,
+
+    /// Send '-d' data verbatim instead of form-encoding it as 'key=value&...'.
+    #[structopt(long = "data-raw")]
+    data_raw: bool,
+
+    /// Send '-d' data verbatim with 'Content-Type: application/json'.
+    #[structopt(long = "json")]
+    json: bool,
+}
+
+/// How `-d`'s content should be turned into a request body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BodyMode {
+    /// Form-encode as 'key1=value1&key2=value2' (the original behavior).
+    Form,
+    /// Send the data unmodified as the body.
+    Raw,
+    /// Send the data unmodified as the body with a JSON content type.
+    Json,
 }
 
 fn main() {
     let args = CurlArgs::from_args();
-    let url_input = args.url;
-    let method = args.method.to_uppercase();
+
+    let config = match &args.config {
+        // An explicit '--config' must exist; a typo'd path is an error, not a silent default.
+        Some(raw) => match load_config(&PathBuf::from(raw)) {
+            Ok(config) => config,
+            Err(e) => {
+                println!("Error: {}", e);
+                return;
+            }
+        },
+        None => match default_config_path() {
+            Some(path) if path.exists() => match load_config(&path) {
+                Ok(config) => config,
+                Err(e) => {
+                    println!("Error: {}", e);
+                    return;
+                }
+            },
+            _ => Config::default(),
+        },
+    };
+
+    let profile = match &args.profile {
+        Some(name) => match config.profiles.get(name) {
+            Some(profile) => profile.clone(),
+            None => {
+                println!("Error: {}", unknown_profile_error(name, &config));
+                return;
+            }
+        },
+        None => Profile::default(),
+    };
+
+    let url_input = match args.url.clone().or_else(|| profile.url.clone()) {
+        Some(url) => url,
+        None => {
+            println!("Error: No URL given on the command line or in the selected profile.");
+            return;
+        }
+    };
+    // Merge order: config defaults < selected profile < explicit CLI flags.
+    let method = args
+        .method
+        .clone()
+        .or_else(|| profile.method.clone())
+        .or_else(|| config.method.clone())
+        .unwrap_or_else(|| "GET".to_string())
+        .to_uppercase();
     let data = args.data;
+    let headers = match merge_headers(&[&config.headers, &profile.headers, &args.headers]) {
+        Ok(headers) => headers,
+        Err(e) => {
+            println!("Error: {}", e);
+            return;
+        }
+    };
+    let proxy = args.proxy.clone().or_else(|| config.proxy.clone());
+    let resolve = args.resolve;
+    let timeout = config.timeout;
+    let body_mode = if args.json {
+        BodyMode::Json
+    } else if args.data_raw {
+        BodyMode::Raw
+    } else {
+        BodyMode::Form
+    };
 
     // Attempt to parse URL
     match Url::parse(&url_input) {
@@ -46,7 +296,14 @@ fn main() {
                 println!("Error: {}", e);
             } else {
                 // Proceed to make request
-                match make_request(&url, &method, data) {
+                let options = RequestOptions {
+                    body_mode,
+                    headers: &headers,
+                    proxy: proxy.as_deref(),
+                    resolve: &resolve,
+                    timeout,
+                };
+                match make_request(&url, &method, data, &options) {
                     Ok(response) => {
                         // Handle response
                         handle_response(&response);
@@ -89,27 +346,7 @@ fn main() {
 // Function to check IP address syntax
 fn check_ip_address(url: &Url) -> Result<(), String> {
     if let Some(host_str) = url.host_str() {
-        // Try to parse as IPv4 address
-        if let Ok(_) = Ipv4Addr::from_str(host_str) {
-            // Valid IPv4 address
-            Ok(())
-        } else if host_str.starts_with('[') && host_str.ends_with(']') {
-            // Possible IPv6 address
-            let ipv6_str = &host_str[1..host_str.len() - 1];
-            if Ipv6Addr::from_str(ipv6_str).is_err() {
-                Err("The URL contains an invalid IPv6 address.".to_string())
-            } else {
-                Ok(())
-            }
-        } else {
-            // Check if host_str is numeric with dots (possible IPv4)
-            if host_str.chars().all(|c| c.is_digit(10) || c == '.') {
-                Err("The URL contains an invalid IPv4 address.".to_string())
-            } else {
-                // Not an IP address, skip checking
-                Ok(())
-            }
-        }
+        Host::from_str(host_str).map(|_| ())
     } else {
         // No host present
         Err("The URL does not contain a host.".to_string())
@@ -131,26 +368,208 @@ fn check_port_number(url: &Url) -> Result<(), String> {
     }
 }
 
-// Function to make HTTP request
-fn make_request(url: &Url, method: &str, data: Option<String>) -> Result<String, String> {
-    let client = Client::new();
-
-    let response = match method {
-        "GET" => client.get(url.as_str()).send(),
-        "POST" => {
-            if let Some(data_str) = data {
-                // Parse data into key-value pairs
-                let params = parse_data(&data_str);
-                client.post(url.as_str()).form(&params).send()
+// Function to parse a '--resolve HOST:PORT:ADDR' entry. ADDR reuses the
+// `Host` parsing so that a bracketed IPv6 literal (e.g. '[::1]') is
+// accepted, but the override itself must name a concrete IP, not a domain.
+fn parse_resolve(raw: &str) -> Result<(String, u16, IpAddr), String> {
+    let mut parts = raw.splitn(3, ':');
+    let host = parts
+        .next()
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| format!("Invalid --resolve entry '{}': missing host.", raw))?;
+    let port_str = parts
+        .next()
+        .ok_or_else(|| format!("Invalid --resolve entry '{}': missing port.", raw))?;
+    let addr_str = parts
+        .next()
+        .ok_or_else(|| format!("Invalid --resolve entry '{}': missing address.", raw))?;
+
+    let port: u16 = port_str.parse().map_err(|_| {
+        format!(
+            "Invalid --resolve entry '{}': '{}' is not a valid port.",
+            raw, port_str
+        )
+    })?;
+
+    match Host::from_str(addr_str)? {
+        Host::Ipv4(addr) => Ok((host.to_string(), port, IpAddr::V4(addr))),
+        Host::Ipv6(addr, None) => Ok((host.to_string(), port, IpAddr::V6(addr))),
+        Host::Ipv6(_, Some(zone)) => Err(format!(
+            "Invalid --resolve entry '{}': zone id '%{}' is not supported in --resolve overrides.",
+            raw, zone
+        )),
+        Host::Domain(_) => Err(format!(
+            "Invalid --resolve entry '{}': '{}' is not an IP address.",
+            raw, addr_str
+        )),
+    }
+}
+
+// Function to build the HTTP client, routing through `proxy` (reqwest picks
+// CONNECT vs. absolute-form per scheme) and pinning any `--resolve` overrides.
+fn build_client(
+    proxy: Option<&str>,
+    resolve: &[String],
+    timeout: Option<u64>,
+) -> Result<Client, String> {
+    let mut builder = Client::builder();
+
+    if let Some(secs) = timeout {
+        builder = builder.timeout(Duration::from_secs(secs));
+    }
+
+    for raw in resolve {
+        let (host, port, addr) = parse_resolve(raw)?;
+        builder = builder.resolve(&host, SocketAddr::new(addr, port));
+    }
+
+    if let Some(proxy_str) = proxy {
+        let proxy_url = Url::parse(proxy_str)
+            .map_err(|e| format!("Invalid proxy URL '{}': {}", proxy_str, e))?;
+
+        if proxy_url.host_str().is_none() {
+            return Err(format!("The proxy URL '{}' is missing a host.", proxy_str));
+        }
+        check_ip_address(&proxy_url)?;
+        check_port_number(&proxy_url)?;
+        if proxy_url.port_or_known_default().is_none() {
+            return Err(format!("The proxy URL '{}' is missing a port.", proxy_str));
+        }
+
+        let proxy = reqwest::Proxy::all(proxy_str)
+            .map_err(|e| format!("Invalid proxy configuration: {}", e))?;
+        builder = builder.proxy(proxy);
+    }
+
+    builder
+        .build()
+        .map_err(|e| format!("Failed to build HTTP client: {}", e))
+}
+
+// Function to rebuild the request URL through `Host` so it round-trips
+// correctly (e.g. an IPv6 literal gets re-wrapped in brackets).
+fn build_request_url(url: &Url) -> Result<String, String> {
+    let host_str = url
+        .host_str()
+        .ok_or_else(|| "The URL does not contain a host.".to_string())?;
+    let host = Host::from_str(host_str)?;
+
+    // Re-include userinfo (e.g. 'user:pass@') so embedded Basic Auth survives the rebuild.
+    let userinfo = if url.username().is_empty() && url.password().is_none() {
+        String::new()
+    } else {
+        match url.password() {
+            Some(password) => format!("{}:{}@", url.username(), password),
+            None => format!("{}@", url.username()),
+        }
+    };
+
+    let mut rebuilt = format!(
+        "{}://{}{}{}",
+        url.scheme(),
+        userinfo,
+        host.to_authority(url.port()),
+        url.path()
+    );
+    if let Some(query) = url.query() {
+        rebuilt.push('?');
+        rebuilt.push_str(query);
+    }
+    if let Some(fragment) = url.fragment() {
+        rebuilt.push('#');
+        rebuilt.push_str(fragment);
+    }
+    Ok(rebuilt)
+}
+
+// Function to check whether `headers` already sets `name` (case-insensitive),
+// so a body mode's implicit header doesn't end up duplicated on the wire.
+fn has_header(headers: &[String], name: &str) -> bool {
+    headers.iter().any(|raw| {
+        parse_header(raw)
+            .map(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+            .unwrap_or(false)
+    })
+}
+
+// Function to apply `-d` data to a request builder according to `mode`. An
+// explicit `-H 'Content-Type: ...'` in `headers` wins over the JSON mode's
+// default, matching the override semantics used for the other header layers.
+fn apply_body(
+    builder: reqwest::blocking::RequestBuilder,
+    data: Option<String>,
+    mode: BodyMode,
+    headers: &[String],
+) -> reqwest::blocking::RequestBuilder {
+    let data_str = match data {
+        Some(data_str) => data_str,
+        None => return builder,
+    };
+
+    match mode {
+        BodyMode::Form => {
+            let params = parse_data(&data_str);
+            builder.form(&params)
+        }
+        BodyMode::Raw => builder.body(data_str),
+        BodyMode::Json => {
+            let builder = if has_header(headers, "Content-Type") {
+                builder
             } else {
-                client.post(url.as_str()).send()
-            }
+                builder.header("Content-Type", "application/json")
+            };
+            builder.body(data_str)
         }
+    }
+}
+
+// Per-request options that aren't the URL/method/data, bundled so
+// `make_request` doesn't grow a positional parameter per flag.
+#[derive(Clone, Copy)]
+struct RequestOptions<'a> {
+    body_mode: BodyMode,
+    headers: &'a [String],
+    proxy: Option<&'a str>,
+    resolve: &'a [String],
+    timeout: Option<u64>,
+}
+
+// Function to make HTTP request
+fn make_request(
+    url: &Url,
+    method: &str,
+    data: Option<String>,
+    options: &RequestOptions,
+) -> Result<String, String> {
+    let RequestOptions {
+        body_mode,
+        headers,
+        proxy,
+        resolve,
+        timeout,
+    } = *options;
+    let client = build_client(proxy, resolve, timeout)?;
+    let request_url = build_request_url(url)?;
+
+    let mut builder = match method {
+        "GET" => client.get(&request_url),
+        "HEAD" => client.head(&request_url),
+        "POST" => apply_body(client.post(&request_url), data, body_mode, headers),
+        "PUT" => apply_body(client.put(&request_url), data, body_mode, headers),
+        "PATCH" => apply_body(client.patch(&request_url), data, body_mode, headers),
+        "DELETE" => apply_body(client.delete(&request_url), data, body_mode, headers),
         _ => {
             return Err(format!("Unsupported HTTP method: {}", method));
         }
     };
 
+    for raw_header in headers {
+        let (name, value) = parse_header(raw_header)?;
+        builder = builder.header(name, value);
+    }
+
+    let response = builder.send();
+
     match response {
         Ok(resp) => {
             if !resp.status().is_success() {
@@ -158,6 +577,21 @@ fn make_request(url: &Url, method: &str, data: Option<String>) -> Result<String,
                     "Request failed with status code: {}.",
                     resp.status().as_u16()
                 ))
+            } else if method == "HEAD" {
+                // HEAD responses have no body, so print the status and the
+                // response headers (sorted, matching the JSON key sort
+                // below) instead of trying to read one.
+                let mut lines = vec![format!("Status: {}", resp.status())];
+                let mut header_pairs: Vec<(&str, &str)> = resp
+                    .headers()
+                    .iter()
+                    .map(|(name, value)| (name.as_str(), value.to_str().unwrap_or("<binary>")))
+                    .collect();
+                header_pairs.sort_by_key(|(name, _)| *name);
+                for (name, value) in header_pairs {
+                    lines.push(format!("{}: {}", name, value));
+                }
+                Ok(lines.join("\n"))
             } else {
                 match resp.text() {
                     Ok(text) => Ok(text),
@@ -175,6 +609,37 @@ fn make_request(url: &Url, method: &str, data: Option<String>) -> Result<String,
     }
 }
 
+// Function to parse a '-H' value into a (name, value) pair, splitting on the
+// first colon and trimming one leading space from the value, matching curl.
+fn parse_header(raw: &str) -> Result<(String, String), String> {
+    let (name, value) = raw
+        .split_once(':')
+        .ok_or_else(|| format!("Invalid header '{}': expected 'Name: Value'.", raw))?;
+    let value = value.strip_prefix(' ').unwrap_or(value);
+    Ok((name.to_string(), value.to_string()))
+}
+
+// Function to merge headers from config, profile, and CLI (in that precedence
+// order), keeping only the last occurrence of each name (case-insensitive) so
+// a higher-precedence layer actually replaces a same-named header instead of
+// just being appended alongside it.
+fn merge_headers(layers: &[&[String]]) -> Result<Vec<String>, String> {
+    let mut order: Vec<String> = Vec::new();
+    let mut by_key: HashMap<String, String> = HashMap::new();
+    for raw in layers.iter().flat_map(|layer| layer.iter()) {
+        let (name, value) = parse_header(raw)?;
+        let key = name.to_ascii_lowercase();
+        if !by_key.contains_key(&key) {
+            order.push(key.clone());
+        }
+        by_key.insert(key, format!("{}: {}", name, value));
+    }
+    Ok(order
+        .into_iter()
+        .map(|key| by_key.remove(&key).unwrap())
+        .collect())
+}
+
 // Function to parse data string into key-value pairs
 fn parse_data(data_str: &str) -> HashMap<String, String> {
     let mut params = HashMap::new();
@@ -230,3 +695,130 @@ fn sort_json(value: &Value) -> Value {
         _ => value.clone(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn host_from_str_parses_ipv4() {
+        assert_eq!(
+            Host::from_str("127.0.0.1").unwrap(),
+            Host::Ipv4(Ipv4Addr::new(127, 0, 0, 1))
+        );
+    }
+
+    #[test]
+    fn host_from_str_parses_bare_ipv6() {
+        assert_eq!(
+            Host::from_str("::1").unwrap(),
+            Host::Ipv6(Ipv6Addr::LOCALHOST, None)
+        );
+    }
+
+    #[test]
+    fn host_from_str_parses_bracketed_ipv6() {
+        assert_eq!(
+            Host::from_str("[::1]").unwrap(),
+            Host::Ipv6(Ipv6Addr::LOCALHOST, None)
+        );
+    }
+
+    #[test]
+    fn host_from_str_parses_ipv6_zone_id_and_displays_it() {
+        let host = Host::from_str("fe80::1%eth0").unwrap();
+        assert_eq!(
+            host,
+            Host::Ipv6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1), Some("eth0".to_string()))
+        );
+        assert_eq!(host.to_string(), "fe80::1%eth0");
+    }
+
+    #[test]
+    fn host_from_str_parses_domain() {
+        assert_eq!(
+            Host::from_str("example.com").unwrap(),
+            Host::Domain("example.com".to_string())
+        );
+    }
+
+    #[test]
+    fn host_to_authority_always_brackets_ipv6() {
+        let host = Host::Ipv6(Ipv6Addr::LOCALHOST, None);
+        assert_eq!(host.to_authority(Some(8080)), "[::1]:8080");
+        assert_eq!(host.to_authority(None), "[::1]");
+    }
+
+    #[test]
+    fn parse_resolve_accepts_ipv4() {
+        assert_eq!(
+            parse_resolve("example.com:443:127.0.0.1").unwrap(),
+            ("example.com".to_string(), 443, IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)))
+        );
+    }
+
+    #[test]
+    fn parse_resolve_accepts_bracketed_ipv6() {
+        assert_eq!(
+            parse_resolve("example.com:443:[::1]").unwrap(),
+            ("example.com".to_string(), 443, IpAddr::V6(Ipv6Addr::LOCALHOST))
+        );
+    }
+
+    #[test]
+    fn parse_resolve_rejects_zone_id() {
+        assert!(parse_resolve("example.com:443:fe80::1%eth0").is_err());
+    }
+
+    #[test]
+    fn parse_resolve_rejects_domain_address() {
+        assert!(parse_resolve("example.com:443:other.example.com").is_err());
+    }
+
+    #[test]
+    fn parse_resolve_rejects_invalid_port() {
+        assert!(parse_resolve("example.com:not-a-port:127.0.0.1").is_err());
+    }
+
+    #[test]
+    fn merge_headers_cli_overrides_same_named_config_header() {
+        let config = vec!["Authorization: config-value".to_string()];
+        let profile: Vec<String> = Vec::new();
+        let cli = vec!["Authorization: cli-value".to_string()];
+        let merged = merge_headers(&[&config, &profile, &cli]).unwrap();
+        assert_eq!(merged, vec!["Authorization: cli-value".to_string()]);
+    }
+
+    #[test]
+    fn merge_headers_profile_overrides_config_and_keeps_distinct_names() {
+        let config = vec![
+            "Authorization: config-value".to_string(),
+            "X-Config-Only: kept".to_string(),
+        ];
+        let profile = vec!["Authorization: profile-value".to_string()];
+        let cli: Vec<String> = Vec::new();
+        let merged = merge_headers(&[&config, &profile, &cli]).unwrap();
+        assert_eq!(
+            merged,
+            vec![
+                "Authorization: profile-value".to_string(),
+                "X-Config-Only: kept".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn merge_headers_rejects_malformed_header() {
+        let config: Vec<String> = Vec::new();
+        let profile: Vec<String> = Vec::new();
+        let cli = vec!["not-a-valid-header".to_string()];
+        assert!(merge_headers(&[&config, &profile, &cli]).is_err());
+    }
+
+    #[test]
+    fn has_header_matches_name_case_insensitively() {
+        let headers = vec!["content-type: text/plain".to_string()];
+        assert!(has_header(&headers, "Content-Type"));
+        assert!(!has_header(&headers, "Authorization"));
+    }
+}